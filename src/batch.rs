@@ -0,0 +1,64 @@
+use crate::compound_factor;
+
+/// Represents `N` independent growth or decay processes that share a principal and time span.
+///
+/// Each process differs only in its rate, which makes this suited to evaluating many
+/// candidate rates in lockstep (e.g. Monte Carlo runs). The rates are stored inline, so the
+/// whole batch stays on the stack.
+#[derive(Clone)]
+pub struct ScenarioBatch<const N: usize> {
+    /// The initial value shared by every process.
+    pub principal: f64,
+    /// The growth or decay rate of each process (as a fraction, e.g., 0.025 for 2.5%).
+    pub rates: [f64; N],
+    /// The time over which the processes occur.
+    pub time: f64,
+}
+
+impl<const N: usize> ScenarioBatch<N> {
+    /// Creates a new instance of `ScenarioBatch`.
+    ///
+    /// # Parameters
+    /// - `principal`: The initial value shared by every process.
+    /// - `rates`: The rate of each process.
+    /// - `time`: The time over which the processes occur.
+    ///
+    /// # Returns
+    /// A new instance of `ScenarioBatch`.
+    pub const fn new(principal: f64, rates: [f64; N], time: f64) -> Self {
+        Self {
+            principal,
+            rates,
+            time,
+        }
+    }
+
+    /// Computes the value of every process at an arbitrary time.
+    ///
+    /// # Parameters
+    /// - `t`: The time at which to evaluate the processes.
+    ///
+    /// # Returns
+    /// The value of each process, in the same order as `rates`.
+    pub fn value_at(&self, t: f64) -> [f64; N] {
+        self.rates
+            .map(|rate| self.principal * compound_factor(rate, t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_EPSILON, ExponentialChange, approx_eq};
+
+    #[test]
+    fn batch_matches_individual_scenarios() {
+        let rates = [-0.1, 0.0, 0.025, 0.07];
+        let batch = ScenarioBatch::new(1000.0, rates, 12.0);
+
+        for (value, rate) in batch.value_at(7.5).into_iter().zip(rates) {
+            let single = ExponentialChange::new(1000.0, None, rate, 12.0);
+            assert!(approx_eq(value, single.value_at(7.5), DEFAULT_EPSILON));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Helpers for radioactive decay problems that go beyond a single `GrowthOrDecayRatios`.
+
+use std::f64::consts::LN_2;
+
+use crate::RatioError;
+
+/// Computes the total remaining amount of a sample made of several decaying components.
+///
+/// Each component decays independently as `initial * 0.5^(t / half_life)`, so a mixture of
+/// isotopes with different half-lives does not decay exponentially as a whole.
+///
+/// # Parameters
+/// - `components`: The `(initial_amount, half_life)` pair of each component.
+/// - `t`: The time elapsed.
+///
+/// # Returns
+/// The summed remaining amount of every component at time `t`.
+pub fn mixed_decay(components: &[(f64, f64)], t: f64) -> f64 {
+    components
+        .iter()
+        .map(|&(initial_amount, half_life)| initial_amount * 0.5_f64.powf(t / half_life))
+        .sum()
+}
+
+/// Computes the half-life that explains an observed remaining fraction after a known time.
+///
+/// # Parameters
+/// - `fraction`: The fraction of the original amount still remaining.
+/// - `time`: The time elapsed.
+///
+/// # Errors
+/// Returns `RatioError::NonPositiveRatio` if `fraction` is zero or negative, or
+/// `RatioError::FractionOutOfRange` if it is 1 or more.
+///
+/// # Returns
+/// The half-life `time * ln(2) / ln(1 / fraction)`.
+pub fn half_life_from_observation(fraction: f64, time: f64) -> Result<f64, RatioError> {
+    if fraction <= 0.0 {
+        return Err(RatioError::NonPositiveRatio);
+    }
+    if fraction >= 1.0 {
+        return Err(RatioError::FractionOutOfRange);
+    }
+
+    Ok(time * LN_2 / fraction.recip().ln())
+}
+
+/// Computes the amount-weighted average apparent age of a sample with several components.
+///
+/// Each component's age is `half_life * ln(1 / fraction_remaining) / ln(2)`.
+///
+/// # Parameters
+/// - `components`: The `(amount, fraction_remaining, half_life)` triple of each component.
+///
+/// # Returns
+/// The average of the component ages, weighted by amount.
+pub fn weighted_age(components: &[(f64, f64, f64)]) -> f64 {
+    let total_amount: f64 = components.iter().map(|&(amount, _, _)| amount).sum();
+    let weighted_ages: f64 = components
+        .iter()
+        .map(|&(amount, fraction_remaining, half_life)| {
+            amount * half_life * fraction_remaining.recip().ln() / LN_2
+        })
+        .sum();
+
+    weighted_ages / total_amount
+}
+
+/// Computes the effective half-life of a nuclide that decays through two competing channels.
+///
+/// The decay constants of the channels add, so the half-lives combine like parallel
+/// resistors: `1 / (1 / t1 + 1 / t2)`.
+///
+/// # Parameters
+/// - `t1`: The partial half-life of the first channel.
+/// - `t2`: The partial half-life of the second channel.
+///
+/// # Returns
+/// The effective half-life, shorter than either partial half-life.
+pub fn combined_half_life(t1: f64, t2: f64) -> f64 {
+    (t1.recip() + t2.recip()).recip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_EPSILON, approx_eq};
+
+    #[test]
+    fn mixed_decay_sums_independent_components() {
+        let components = [(100.0, 5.0), (50.0, 20.0)];
+
+        assert_eq!(mixed_decay(&components, 0.0), 150.0);
+        assert!(approx_eq(
+            mixed_decay(&components, 10.0),
+            100.0 * 0.25 + 50.0 * 0.5_f64.sqrt(),
+            DEFAULT_EPSILON
+        ));
+    }
+
+    #[test]
+    fn half_life_from_observation_recovers_carbon_14() {
+        let fraction = 0.5_f64.powf(10_000.0 / 5730.0);
+        let half_life = half_life_from_observation(fraction, 10_000.0).unwrap();
+
+        assert!(approx_eq(half_life, 5730.0, DEFAULT_EPSILON));
+        assert_eq!(
+            half_life_from_observation(0.0, 10_000.0),
+            Err(RatioError::NonPositiveRatio)
+        );
+        assert_eq!(
+            half_life_from_observation(1.0, 10_000.0),
+            Err(RatioError::FractionOutOfRange)
+        );
+    }
+
+    #[test]
+    fn weighted_age_of_equal_amounts_is_the_mean_age() {
+        // One component is a half-life of 5730 old, the other two half-lives of 1000.
+        let age = weighted_age(&[(10.0, 0.5, 5730.0), (10.0, 0.25, 1000.0)]);
+        assert!(approx_eq(age, (5730.0 + 2000.0) / 2.0, DEFAULT_EPSILON));
+    }
+
+    #[test]
+    fn combined_half_life_of_equal_channels_is_halved() {
+        assert!(approx_eq(
+            combined_half_life(30.0, 30.0),
+            15.0,
+            DEFAULT_EPSILON
+        ));
+        assert!(combined_half_life(30.0, 60.0) < 30.0);
+    }
+}
@@ -0,0 +1,286 @@
+//! Financial helpers built around the crate's growth calculations.
+
+use crate::numeric::solve_time;
+use crate::{DEFAULT_EPSILON, RATE_SEARCH_BOUNDS, SOLVER_MAX_ITERATIONS, compound_factor};
+
+/// Computes the capital required to fund an income stream at a given withdrawal rate.
+///
+/// # Parameters
+/// - `annual_income`: The income to withdraw each year.
+/// - `withdrawal_rate`: The fraction of capital withdrawn each year (e.g., 0.04 for 4%).
+///
+/// # Returns
+/// The capital `annual_income / withdrawal_rate`.
+pub fn capital_for_income(annual_income: f64, withdrawal_rate: f64) -> f64 {
+    annual_income / withdrawal_rate
+}
+
+/// Computes the income a given amount of capital sustains at a withdrawal rate.
+///
+/// # Parameters
+/// - `capital`: The capital available.
+/// - `withdrawal_rate`: The fraction of capital withdrawn each year (e.g., 0.04 for 4%).
+///
+/// # Returns
+/// The annual income `capital * withdrawal_rate`.
+pub fn sustainable_income(capital: f64, withdrawal_rate: f64) -> f64 {
+    capital * withdrawal_rate
+}
+
+/// Computes how long a balance lasts under constant withdrawals while it grows.
+///
+/// The balance grows by `rate` each period and `withdrawal` is taken at the end of the
+/// period, so the balance after `n` periods is
+/// `principal * (1 + rate)^n - withdrawal * ((1 + rate)^n - 1) / rate`.
+///
+/// # Parameters
+/// - `principal`: The starting balance.
+/// - `rate`: The growth rate per period.
+/// - `withdrawal`: The amount withdrawn each period.
+///
+/// # Returns
+/// The (fractional) number of periods until the balance reaches zero, or `None` if the
+/// growth covers the withdrawals and the balance never depletes.
+pub fn depletion_time(principal: f64, rate: f64, withdrawal: f64) -> Option<f64> {
+    if withdrawal <= 0.0 {
+        return None;
+    }
+    if rate.abs() < DEFAULT_EPSILON {
+        return Some(principal / withdrawal);
+    }
+
+    let shortfall = withdrawal - principal * rate;
+    if shortfall <= 0.0 {
+        return None;
+    }
+
+    Some((withdrawal / shortfall).ln() / rate.ln_1p())
+}
+
+/// Computes the deposit needed each period to accumulate a goal.
+///
+/// Solves the future value of an ordinary annuity,
+/// `goal = deposit * ((1 + rate)^periods - 1) / rate`, for the deposit.
+///
+/// # Parameters
+/// - `goal`: The balance to reach.
+/// - `rate`: The growth rate per period.
+/// - `periods`: The number of deposits.
+///
+/// # Returns
+/// The deposit per period, or `goal / periods` when the rate is zero.
+pub fn required_deposit(goal: f64, rate: f64, periods: f64) -> f64 {
+    if rate.abs() < DEFAULT_EPSILON {
+        return goal / periods;
+    }
+
+    goal * rate / (compound_factor(rate, periods) - 1.0)
+}
+
+/// Computes the net present value of a series of cash flows.
+///
+/// # Parameters
+/// - `rate`: The discount rate per period.
+/// - `cash_flows`: The cash flow of each period, negative for outflows. The first flow is at
+///   period 0 and is not discounted.
+///
+/// # Returns
+/// The sum of each flow `cash_flows[i] / (1 + rate)^i`.
+pub fn npv(rate: f64, cash_flows: &[f64]) -> f64 {
+    cash_flows
+        .iter()
+        .enumerate()
+        .map(|(period, cash_flow)| cash_flow / compound_factor(rate, period as f64))
+        .sum()
+}
+
+/// Computes the internal rate of return of a series of cash flows.
+///
+/// Finds the discount rate at which the `npv` of the flows is zero with the `solve_time`
+/// bisection solver, searching rates from -99% to 1000% per period. The first flow is at
+/// period 0 and is not discounted.
+///
+/// # Parameters
+/// - `cash_flows`: The cash flow of each period, negative for outflows.
+///
+/// # Returns
+/// The internal rate of return, or `None` if the net present value does not change sign over
+/// the search range.
+pub fn irr(cash_flows: &[f64]) -> Option<f64> {
+    solve_time(
+        |rate| npv(rate, cash_flows),
+        0.0,
+        RATE_SEARCH_BOUNDS,
+        SOLVER_MAX_ITERATIONS,
+    )
+}
+
+/// Computes the drift-adjusted growth of a geometric Brownian motion.
+///
+/// Under geometric Brownian motion the log of the value grows at `mu - sigma^2 / 2`, so this is
+/// `e^(E[ln V(t)])`, which is also the median outcome. Volatility drags it below the arithmetic
+/// mean `principal * e^(mu * t)`, and the two agree when `sigma` is zero.
+///
+/// # Parameters
+/// - `principal`: The initial value.
+/// - `mu`: The drift per unit of time.
+/// - `sigma`: The volatility per square root of time.
+/// - `t`: The time elapsed.
+///
+/// # Returns
+/// The value `principal * e^((mu - sigma^2 / 2) * t)`.
+pub fn gbm_expected_value(principal: f64, mu: f64, sigma: f64, t: f64) -> f64 {
+    principal * ((mu - 0.5 * sigma * sigma) * t).exp()
+}
+
+/// Computes the level payment a lump sum supports over a number of periods.
+///
+/// This is the standard amortization formula used for loan and pension payments.
+///
+/// # Parameters
+/// - `principal`: The lump sum, e.g. the loan amount.
+/// - `rate`: The interest rate per period.
+/// - `periods`: The number of payments.
+///
+/// # Returns
+/// The payment `principal * rate / (1 - (1 + rate)^-periods)`, or `principal / periods` when
+/// the rate is zero.
+pub fn annuity_payment(principal: f64, rate: f64, periods: f64) -> f64 {
+    if rate.abs() < DEFAULT_EPSILON {
+        return principal / periods;
+    }
+
+    principal * rate / (1.0 - compound_factor(rate, -periods))
+}
+
+/// Computes the all-in effective rate of a product with a front-end load and an ongoing fee.
+///
+/// The load is deducted from the amount invested, then the remainder grows at the gross rate
+/// net of the fee (as in `ExponentialChange::net_of_fees`), over a standardized single period.
+///
+/// # Parameters
+/// - `gross_rate`: The headline growth rate per period.
+/// - `annual_fee`: The ongoing fee per period (as a fraction, e.g., 0.01 for 1%).
+/// - `front_load`: The fraction of the investment taken up front (e.g., 0.05 for 5%).
+///
+/// # Returns
+/// The effective rate `(1 - front_load) * (1 + gross_rate) / (1 + annual_fee) - 1`.
+pub fn bundle_effective_rate(gross_rate: f64, annual_fee: f64, front_load: f64) -> f64 {
+    (1.0 - front_load) * (1.0 + gross_rate) / (1.0 + annual_fee) - 1.0
+}
+
+/// Computes the smallest principal whose growth covers a withdrawal forever.
+///
+/// # Parameters
+/// - `annual_withdrawal`: The amount withdrawn each year.
+/// - `rate`: The growth rate per year.
+///
+/// # Returns
+/// The perpetual principal `annual_withdrawal / rate`, or `None` if the rate is not positive.
+pub fn perpetual_principal(annual_withdrawal: f64, rate: f64) -> Option<f64> {
+    (rate > 0.0).then(|| annual_withdrawal / rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::LN_2;
+
+    use super::*;
+    use crate::approx_eq;
+
+    #[test]
+    fn four_percent_rule_round_trips() {
+        let capital = capital_for_income(40_000.0, 0.04);
+        assert!(approx_eq(capital, 1_000_000.0, DEFAULT_EPSILON));
+        assert!(approx_eq(
+            sustainable_income(capital, 0.04),
+            40_000.0,
+            DEFAULT_EPSILON
+        ));
+    }
+
+    #[test]
+    fn depletion_time_finds_when_the_balance_runs_out() {
+        let periods = depletion_time(100_000.0, 0.05, 10_000.0).unwrap();
+        let growth = compound_factor(0.05, periods);
+        let balance = 100_000.0 * growth - 10_000.0 * (growth - 1.0) / 0.05;
+
+        assert!(approx_eq(periods, LN_2 / 1.05_f64.ln(), DEFAULT_EPSILON));
+        assert!(balance.abs() < 1e-6, "got {balance}");
+    }
+
+    #[test]
+    fn depletion_time_is_none_when_growth_covers_withdrawals() {
+        assert_eq!(depletion_time(100_000.0, 0.05, 4_000.0), None);
+        assert_eq!(depletion_time(100_000.0, 0.05, 5_000.0), None);
+    }
+
+    #[test]
+    fn required_deposit_reaches_a_million_in_thirty_years() {
+        // Saving monthly at 6% a year for 30 years.
+        let deposit = required_deposit(1_000_000.0, 0.06 / 12.0, 360.0);
+        assert!((deposit - 995.51).abs() < 0.01, "got {deposit}");
+
+        // Depositing at the end of each month and growing the balance reaches the goal.
+        let balance = (0..360).fold(0.0, |balance, _| balance * (1.0 + 0.06 / 12.0) + deposit);
+        assert!(approx_eq(balance, 1_000_000.0, DEFAULT_EPSILON));
+    }
+
+    #[test]
+    fn irr_of_a_par_bond_is_its_coupon_rate() {
+        // A bond bought at par with a 10% coupon yields exactly 10%.
+        let rate = irr(&[-1000.0, 100.0, 100.0, 1100.0]).unwrap();
+        assert!(approx_eq(rate, 0.1, 1e-9), "got {rate}");
+
+        assert_eq!(irr(&[100.0, 100.0]), None);
+    }
+
+    #[test]
+    fn npv_matches_a_hand_computed_series_at_ten_percent() {
+        // -1000 + 500 / 1.1 + 400 / 1.21 + 300 / 1.331
+        let value = npv(0.1, &[-1000.0, 500.0, 400.0, 300.0]);
+        assert!((value - 10.5184).abs() < 1e-4, "got {value}");
+    }
+
+    #[test]
+    fn gbm_without_volatility_is_plain_continuous_growth() {
+        let value = gbm_expected_value(1000.0, 0.05, 0.0, 10.0);
+        assert!(approx_eq(value, 1000.0 * 0.5_f64.exp(), DEFAULT_EPSILON));
+
+        assert!(gbm_expected_value(1000.0, 0.05, 0.2, 10.0) < value);
+    }
+
+    #[test]
+    fn annuity_payment_matches_a_thirty_year_mortgage() {
+        // A $200,000 loan at 6% a year, paid monthly for 30 years, costs $1,199.10 a month.
+        let payment = annuity_payment(200_000.0, 0.06 / 12.0, 360.0);
+        assert!((payment - 1199.10).abs() < 0.005, "got {payment}");
+
+        assert_eq!(annuity_payment(1200.0, 0.0, 12.0), 100.0);
+    }
+
+    #[test]
+    fn a_front_load_lowers_the_effective_rate_below_the_headline() {
+        let effective_rate = bundle_effective_rate(0.08, 0.01, 0.05);
+
+        assert!(effective_rate < 0.08);
+        assert!(approx_eq(
+            effective_rate,
+            0.95 * 1.08 / 1.01 - 1.0,
+            DEFAULT_EPSILON
+        ));
+        assert!(approx_eq(
+            bundle_effective_rate(0.08, 0.0, 0.0),
+            0.08,
+            DEFAULT_EPSILON
+        ));
+    }
+
+    #[test]
+    fn perpetual_principal_for_forty_thousand_at_four_percent() {
+        let principal = perpetual_principal(40_000.0, 0.04).unwrap();
+
+        assert!(approx_eq(principal, 1_000_000.0, DEFAULT_EPSILON));
+        assert_eq!(perpetual_principal(40_000.0, 0.0), None);
+    }
+}
@@ -0,0 +1,82 @@
+//! Numeric solvers for models without a closed-form inverse.
+
+use crate::DEFAULT_EPSILON;
+
+/// Finds the time at which a monotonic function reaches a target, by bisection.
+///
+/// # Parameters
+/// - `value_fn`: The function to invert, e.g. a model's value over time.
+/// - `target`: The value to reach.
+/// - `bounds`: The `(low, high)` interval to search.
+/// - `max_iter`: The largest number of bisection steps to take.
+///
+/// # Returns
+/// The time at which `value_fn` reaches `target` (within `DEFAULT_EPSILON`, or to the precision
+/// reached after `max_iter` steps), which may be either end of `bounds`, or `None` if
+/// `value_fn - target` does not change sign over `bounds`.
+pub fn solve_time<F: Fn(f64) -> f64>(
+    value_fn: F,
+    target: f64,
+    bounds: (f64, f64),
+    max_iter: usize,
+) -> Option<f64> {
+    let (mut low, mut high) = bounds;
+    let mut error_low = value_fn(low) - target;
+    let error_high = value_fn(high) - target;
+
+    // An endpoint that is already a root has no sign change to bisect towards.
+    if error_low.abs() < DEFAULT_EPSILON {
+        return Some(low);
+    }
+    if error_high.abs() < DEFAULT_EPSILON {
+        return Some(high);
+    }
+    if error_low * error_high > 0.0 {
+        return None;
+    }
+
+    for _ in 0..max_iter {
+        let mid = (low + high) / 2.0;
+        let error_mid = value_fn(mid) - target;
+        if error_mid.abs() < DEFAULT_EPSILON {
+            return Some(mid);
+        }
+
+        if error_low * error_mid < 0.0 {
+            high = mid;
+        } else {
+            low = mid;
+            error_low = error_mid;
+        }
+    }
+
+    Some((low + high) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq;
+
+    #[test]
+    fn solve_time_finds_the_root_of_a_monotonic_function() {
+        let time = solve_time(|t| 100.0 * 1.05_f64.powf(t), 200.0, (0.0, 100.0), 200).unwrap();
+        assert!(
+            approx_eq(time, 2.0_f64.ln() / 1.05_f64.ln(), 1e-9),
+            "got {time}"
+        );
+    }
+
+    #[test]
+    fn solve_time_is_none_without_a_root_in_bounds() {
+        assert_eq!(solve_time(|t| t * t + 1.0, 0.0, (-5.0, 5.0), 200), None);
+        assert_eq!(solve_time(|t| t, 10.0, (0.0, 5.0), 200), None);
+    }
+
+    #[test]
+    fn solve_time_returns_a_root_at_either_endpoint() {
+        assert_eq!(solve_time(|t| t, 0.0, (0.0, 1.0), 60), Some(0.0));
+        assert_eq!(solve_time(|t| t - 2.0, 0.0, (2.0, 5.0), 60), Some(2.0));
+        assert_eq!(solve_time(|t| t - 5.0, 0.0, (2.0, 5.0), 60), Some(5.0));
+    }
+}
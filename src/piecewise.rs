@@ -0,0 +1,128 @@
+use std::f64::consts::LN_2;
+
+use crate::compound_factor;
+
+/// Represents a growth or decay process whose rate changes at known times.
+///
+/// The process holds its principal until the first segment starts, then compounds at each
+/// segment's rate until the next segment begins. This models schedules such as variable-rate
+/// loans or changing population dynamics. One-time shocks, such as a market crash, can also
+/// scale the value at given times.
+#[derive(Clone)]
+pub struct PiecewiseExponential {
+    /// The initial value before the first segment starts.
+    pub principal: f64,
+    /// The `(start_time, rate)` pair of each segment, sorted by start time.
+    pub segments: Vec<(f64, f64)>,
+    /// The `(time, multiplier)` pair of each one-time shock, applied to the value after that time.
+    pub shocks: Vec<(f64, f64)>,
+}
+
+impl PiecewiseExponential {
+    /// Creates a new instance of `PiecewiseExponential`.
+    ///
+    /// # Parameters
+    /// - `principal`: The initial value before the first segment starts.
+    /// - `segments`: The `(start_time, rate)` pair of each segment.
+    ///
+    /// # Panics
+    /// Panics if the segments are not sorted by start time.
+    ///
+    /// # Returns
+    /// A new instance of `PiecewiseExponential` without any shocks.
+    pub fn new(principal: f64, segments: Vec<(f64, f64)>) -> Self {
+        assert!(
+            segments.windows(2).all(|pair| pair[0].0 <= pair[1].0),
+            "Segments must be sorted by start time."
+        );
+
+        Self {
+            principal,
+            segments,
+            shocks: Vec::new(),
+        }
+    }
+
+    /// Computes the value of the process at an arbitrary time.
+    ///
+    /// # Parameters
+    /// - `t`: The time at which to evaluate the process.
+    ///
+    /// # Returns
+    /// The principal compounded through every segment that has started by `t`, and scaled by
+    /// every shock that occurred before `t`.
+    pub fn value_at(&self, t: f64) -> f64 {
+        let mut value = self.principal;
+
+        for (index, &(start, rate)) in self.segments.iter().enumerate() {
+            if t <= start {
+                break;
+            }
+
+            // Each segment runs until the next one starts, or until `t` if that comes first.
+            let end = self
+                .segments
+                .get(index + 1)
+                .map_or(t, |&(next_start, _)| next_start.min(t));
+            value *= compound_factor(rate, end - start);
+        }
+
+        for &(shock_time, multiplier) in &self.shocks {
+            if shock_time < t {
+                value *= multiplier;
+            }
+        }
+
+        value
+    }
+
+    /// Computes the doubling time implied by the rate active at a time.
+    ///
+    /// # Parameters
+    /// - `t`: The time at which to look up the active segment.
+    ///
+    /// # Returns
+    /// `Some(ln(2) / ln(1 + rate))` for the latest segment starting at or before `t`, or `None`
+    /// if that segment does not grow or no segment has started yet.
+    pub fn doubling_time_at(&self, t: f64) -> Option<f64> {
+        let &(_, rate) = self.segments.iter().rev().find(|&&(start, _)| start <= t)?;
+        let continuous_rate = rate.ln_1p();
+        (continuous_rate > 0.0).then(|| LN_2 / continuous_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_EPSILON, approx_eq};
+
+    #[test]
+    fn two_segments_match_a_manual_two_phase_computation() {
+        let piecewise = PiecewiseExponential::new(1000.0, vec![(0.0, 0.05), (4.0, -0.02)]);
+        let after_first_phase = 1000.0 * 1.05_f64.powi(4);
+
+        assert!(approx_eq(
+            piecewise.value_at(4.0),
+            after_first_phase,
+            DEFAULT_EPSILON
+        ));
+        assert!(approx_eq(
+            piecewise.value_at(10.0),
+            after_first_phase * 0.98_f64.powi(6),
+            DEFAULT_EPSILON
+        ));
+    }
+
+    #[test]
+    fn doubling_time_is_shorter_in_a_faster_segment() {
+        let piecewise =
+            PiecewiseExponential::new(100.0, vec![(2.0, 0.03), (5.0, 0.09), (8.0, -0.02)]);
+        let slow = piecewise.doubling_time_at(3.0).unwrap();
+        let fast = piecewise.doubling_time_at(5.0).unwrap();
+
+        assert!(fast < slow);
+        assert!(approx_eq(fast, LN_2 / 1.09_f64.ln(), DEFAULT_EPSILON));
+        assert_eq!(piecewise.doubling_time_at(1.0), None);
+        assert_eq!(piecewise.doubling_time_at(9.0), None);
+    }
+}
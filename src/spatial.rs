@@ -0,0 +1,44 @@
+//! Exponential attenuation over distance, such as light passing through a material.
+
+/// Computes the intensity remaining after passing through an attenuating medium.
+///
+/// # Parameters
+/// - `i0`: The initial intensity.
+/// - `mu`: The attenuation coefficient, per unit of distance.
+/// - `x`: The distance travelled through the medium.
+///
+/// # Returns
+/// The intensity `i0 * e^(-mu * x)`.
+pub fn attenuation(i0: f64, mu: f64, x: f64) -> f64 {
+    i0 * (-mu * x).exp()
+}
+
+/// Computes the penetration depth, the distance over which intensity falls by a factor of `e`.
+///
+/// # Parameters
+/// - `mu`: The attenuation coefficient, per unit of distance.
+///
+/// # Returns
+/// The depth `1 / mu`.
+pub fn penetration_depth(mu: f64) -> f64 {
+    mu.recip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_EPSILON, approx_eq};
+
+    #[test]
+    fn intensity_falls_to_one_over_e_at_the_penetration_depth() {
+        let (i0, mu) = (250.0, 0.4);
+        let depth = penetration_depth(mu);
+
+        assert!(approx_eq(
+            attenuation(i0, mu, depth),
+            i0 / std::f64::consts::E,
+            DEFAULT_EPSILON
+        ));
+        assert_eq!(attenuation(i0, mu, 0.0), i0);
+    }
+}